@@ -68,6 +68,23 @@ impl Dit {
     pub fn is_supported(&self) -> bool {
         self.supported.get()
     }
+
+    /// Check if enabling DIT takes effect on this CPU.
+    ///
+    /// Enables DIT, reads back the `PSTATE.DIT` bit, and then returns DIT to its
+    /// previous state.
+    ///
+    /// Note that this only confirms that the CPU accepts the setting. Whether DIT
+    /// actually changes the timing behavior of instructions is a property of the
+    /// microarchitecture which can not be reliably determined at runtime.
+    pub fn effective(&self) -> bool {
+        if !self.is_supported() {
+            return false;
+        }
+
+        let _guard = self.enable();
+        self.is_enabled()
+    }
 }
 
 /// RAII guard which returns DIT to its previous state when dropped.
@@ -145,6 +162,17 @@ mod tests {
         assert!(!dit.is_enabled());
     }
 
+    #[test]
+    fn effective() {
+        let dit = Dit::init();
+        assert!(dit.effective());
+        assert!(!dit.is_enabled());
+
+        let _guard = dit.enable();
+        assert!(dit.effective());
+        assert!(dit.is_enabled());
+    }
+
     #[test]
     fn asm_wrappers() {
         let dit_token = dit_supported::init();
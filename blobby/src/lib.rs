@@ -42,6 +42,17 @@
 //! assert_eq!(v.next(), None);
 //! ```
 //!
+//! # Checksum header
+//! With the `checksum` feature enabled, `encode_blobs_with_checksum`
+//! prepends a 9-byte header to the encoded data: the magic bytes `BLBY`,
+//! a format version byte, and a big-endian CRC-32 of the remaining payload.
+//! `BlobIterator::new` validates the header when the data starts with the
+//! magic bytes and parses the data as a legacy headerless blob otherwise.
+//!
+//! Note that legacy data whose first bytes happen to be `BLBY` can not be
+//! distinguished from checksummed data and will most likely fail header
+//! validation. Such data can only be parsed with the feature disabled.
+//!
 //! [0]: https://en.wikipedia.org/wiki/Variable-length_quantity
 #![no_std]
 #![doc(
@@ -70,6 +81,53 @@ pub enum Error {
     UnexpectedEnd,
     /// Not enough elements for `BlobNIterator`
     NotEnoughElements,
+    /// Hex string has odd length
+    OddHexLength,
+    /// Hex string contains non-hex character
+    InvalidHexChar,
+    /// Unsupported checksum header version
+    InvalidVersion,
+    /// Payload checksum does not match the header
+    ChecksumMismatch,
+}
+
+#[cfg(feature = "checksum")]
+const MAGIC: &[u8; 4] = b"BLBY";
+#[cfg(feature = "checksum")]
+const VERSION: u8 = 1;
+#[cfg(feature = "checksum")]
+const HEADER_LEN: usize = MAGIC.len() + 1 + 4;
+
+/// Compute CRC-32 (ISO-HDLC) of `data`.
+#[cfg(feature = "checksum")]
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &b in data {
+        crc ^= b as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+        }
+    }
+    !crc
+}
+
+/// Strip and validate the checksum header if `data` starts with it.
+#[cfg(feature = "checksum")]
+fn strip_header(data: &[u8]) -> Result<&[u8], Error> {
+    if data.len() < HEADER_LEN || &data[..MAGIC.len()] != MAGIC {
+        return Ok(data);
+    }
+    if data[MAGIC.len()] != VERSION {
+        return Err(Error::InvalidVersion);
+    }
+    let mut crc = [0u8; 4];
+    crc.copy_from_slice(&data[MAGIC.len() + 1..HEADER_LEN]);
+    let payload = &data[HEADER_LEN..];
+    if crc32(payload) != u32::from_be_bytes(crc) {
+        return Err(Error::ChecksumMismatch);
+    }
+    Ok(payload)
 }
 
 const NEXT_MASK: u8 = 0b1000_0000;
@@ -206,9 +264,28 @@ where
     (out_buf, idx_len)
 }
 
+/// Encode the given collection of binary blobs like [`encode_blobs`] and
+/// prefix the result with the checksum header described in the crate docs.
+#[cfg(feature = "checksum")]
+pub fn encode_blobs_with_checksum<'a, I, T>(blobs: &'a I) -> (Vec<u8>, usize)
+where
+    &'a I: IntoIterator<Item = &'a T>,
+    T: AsRef<[u8]> + 'a,
+{
+    let (payload, idx_len) = encode_blobs(blobs);
+    let mut out_buf = Vec::with_capacity(HEADER_LEN + payload.len());
+    out_buf.extend_from_slice(MAGIC);
+    out_buf.push(VERSION);
+    out_buf.extend_from_slice(&crc32(&payload).to_be_bytes());
+    out_buf.extend_from_slice(&payload);
+    (out_buf, idx_len)
+}
+
 impl<'a> BlobIterator<'a> {
     /// Create new `BlobIterator` for given `data`.
     pub fn new(data: &'a [u8]) -> Result<Self, Error> {
+        #[cfg(feature = "checksum")]
+        let data = strip_header(data)?;
         let mut pos = 0;
         let dedup_n = read_vlq(data, &mut pos)?;
 
@@ -263,6 +340,44 @@ impl<'a> Iterator for BlobIterator<'a> {
     }
 }
 
+/// Encode blobs given as hex strings in .blb format.
+///
+/// Each string is decoded and the result is passed to [`encode_blobs`].
+pub fn encode_hex_blobs(hex_strs: &[&str]) -> Result<Vec<u8>, Error> {
+    let blobs = hex_strs
+        .iter()
+        .map(|s| decode_hex(s))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(encode_blobs(&blobs).0)
+}
+
+fn decode_hex(s: &str) -> Result<Vec<u8>, Error> {
+    fn nibble(c: u8) -> Result<u8, Error> {
+        match c {
+            b'0'..=b'9' => Ok(c - b'0'),
+            b'a'..=b'f' => Ok(c - b'a' + 10),
+            b'A'..=b'F' => Ok(c - b'A' + 10),
+            _ => Err(Error::InvalidHexChar),
+        }
+    }
+
+    let s = s.as_bytes();
+    if s.len() & 1 != 0 {
+        return Err(Error::OddHexLength);
+    }
+    s.chunks_exact(2)
+        .map(|c| Ok((nibble(c[0])? << 4) | nibble(c[1])?))
+        .collect()
+}
+
+/// Parse `data` into a table of blobs with de-duplicated entries resolved.
+///
+/// Returned slices borrow from `data`, so unlike [`BlobIterator`] the table
+/// provides O(1) random access to stored blobs without cloning them.
+pub fn index_table(data: &[u8]) -> Result<Vec<&[u8]>, Error> {
+    BlobIterator::new(data)?.collect()
+}
+
 // TODO: use const generics on stabilization
 // docs are not generated due to https://github.com/rust-lang/rust/issues/52607
 macro_rules! new_iter {
@@ -308,7 +423,9 @@ new_iter!(Blob6Iterator, 6);
 
 #[cfg(test)]
 mod tests {
-    use super::{read_vlq, Error, NEXT_MASK, VAL_MASK};
+    use super::{
+        encode_blobs, encode_hex_blobs, index_table, read_vlq, Error, NEXT_MASK, VAL_MASK,
+    };
 
     fn encode_vlq(mut val: usize, buf: &mut [u8; 4]) -> &[u8] {
         macro_rules! step {
@@ -385,4 +502,60 @@ mod tests {
         assert_eq!(read_vlq(&examples, &mut pos), Err(Error::InvalidVlq));
         assert_eq!(pos, 25);
     }
+
+    #[test]
+    fn index_table_dedup() {
+        let blobs: [&[u8]; 6] = [b"hello", b"world!", b"hello", b"", b":::", b"world!"];
+        let (data, idx_len) = encode_blobs(&blobs);
+        assert_eq!(idx_len, 2);
+
+        let table = index_table(&data).unwrap();
+        assert_eq!(table, blobs);
+        assert_eq!(table[4], b":::");
+        assert_eq!(table[5], b"world!");
+    }
+
+    #[test]
+    fn hex_blobs_roundtrip() {
+        let hex_strs = ["00010203", "", "DEADbeef", "00010203"];
+        let data = encode_hex_blobs(&hex_strs).unwrap();
+
+        let table = index_table(&data).unwrap();
+        let expected: [&[u8]; 4] = [&[0, 1, 2, 3], &[], &[0xDE, 0xAD, 0xBE, 0xEF], &[0, 1, 2, 3]];
+        assert_eq!(table, expected);
+
+        assert_eq!(encode_hex_blobs(&["abc"]), Err(Error::OddHexLength));
+        assert_eq!(encode_hex_blobs(&["0g"]), Err(Error::InvalidHexChar));
+    }
+
+    #[cfg(feature = "checksum")]
+    #[test]
+    fn checksum_header() {
+        use super::{crc32, encode_blobs_with_checksum, HEADER_LEN};
+
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+
+        let blobs: [&[u8]; 3] = [b"hello", b"world!", b"hello"];
+        let (mut data, _) = encode_blobs_with_checksum(&blobs);
+        assert_eq!(&data[..5], b"BLBY\x01");
+        assert_eq!(&data[HEADER_LEN..], &encode_blobs(&blobs).0[..]);
+        assert_eq!(index_table(&data).unwrap(), blobs);
+
+        data[HEADER_LEN + 3] ^= 1;
+        assert_eq!(index_table(&data), Err(Error::ChecksumMismatch));
+
+        data[4] = 2;
+        assert_eq!(index_table(&data), Err(Error::InvalidVersion));
+    }
+
+    #[cfg(feature = "checksum")]
+    #[test]
+    fn checksum_legacy() {
+        let data = b"\x02\x05hello\x06world!\x01\x02 \x00\x03\x06:::\x03\x01\x00";
+        let table = index_table(data).unwrap();
+        let expected: [&[u8]; 8] = [
+            b"hello", b" ", b"", b"world!", b":::", b"world!", b"hello", b"",
+        ];
+        assert_eq!(table, expected);
+    }
 }
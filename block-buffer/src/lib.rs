@@ -235,6 +235,46 @@ impl<BS: ArraySize, K: BufferKind> BlockBuffer<BS, K> {
         res
     }
 
+    /// Compress remaining data after padding it with `delim`, zeros and
+    /// the `suffix` bytes. If there is not enough unused space, `compress`
+    /// will be called twice.
+    ///
+    /// Lazy buffers may be completely full, in which case the buffered block
+    /// is compressed first and padding is written into a separate block.
+    ///
+    /// # Panics
+    /// If suffix length is bigger than block size.
+    #[inline(always)]
+    pub fn digest_pad(
+        &mut self,
+        delim: u8,
+        suffix: &[u8],
+        mut compress: impl FnMut(&Array<u8, BS>),
+    ) {
+        if suffix.len() > BS::USIZE {
+            panic!("suffix is too long");
+        }
+        if self.get_pos() == BS::USIZE {
+            let block = self.pad_with_zeros();
+            compress(&block);
+        }
+        let pos = self.get_pos();
+        let mut buf = self.pad_with_zeros();
+        buf[pos] = delim;
+
+        let n = self.size() - suffix.len();
+        if self.size() - pos - 1 < suffix.len() {
+            compress(&buf);
+            buf.fill(0);
+            buf[n..].copy_from_slice(suffix);
+            compress(&buf);
+        } else {
+            buf[n..].copy_from_slice(suffix);
+            compress(&buf);
+        }
+        self.reset();
+    }
+
     /// Return current cursor position.
     #[inline(always)]
     pub fn get_pos(&self) -> usize {
@@ -278,6 +318,14 @@ impl<BS: ArraySize, K: BufferKind> BlockBuffer<BS, K> {
         BS::USIZE
     }
 
+    /// Return number of blocks which would be passed to the `compress`
+    /// function by [`digest_blocks`][Self::digest_blocks] for input
+    /// of length `input_len`.
+    #[inline(always)]
+    pub fn blocks_for(&self, input_len: usize) -> usize {
+        K::blocks_for(self.get_pos(), input_len, BS::USIZE)
+    }
+
     /// Return number of remaining bytes in the internal buffer.
     #[inline(always)]
     pub fn remaining(&self) -> usize {
@@ -313,39 +361,6 @@ impl<BS: ArraySize, K: BufferKind> BlockBuffer<BS, K> {
 }
 
 impl<BS: ArraySize> BlockBuffer<BS, Eager> {
-    /// Compress remaining data after padding it with `delim`, zeros and
-    /// the `suffix` bytes. If there is not enough unused space, `compress`
-    /// will be called twice.
-    ///
-    /// # Panics
-    /// If suffix length is bigger than block size.
-    #[inline(always)]
-    pub fn digest_pad(
-        &mut self,
-        delim: u8,
-        suffix: &[u8],
-        mut compress: impl FnMut(&Array<u8, BS>),
-    ) {
-        if suffix.len() > BS::USIZE {
-            panic!("suffix is too long");
-        }
-        let pos = self.get_pos();
-        let mut buf = self.pad_with_zeros();
-        buf[pos] = delim;
-
-        let n = self.size() - suffix.len();
-        if self.size() - pos - 1 < suffix.len() {
-            compress(&buf);
-            buf.fill(0);
-            buf[n..].copy_from_slice(suffix);
-            compress(&buf);
-        } else {
-            buf[n..].copy_from_slice(suffix);
-            compress(&buf);
-        }
-        self.reset();
-    }
-
     /// Pad message with 0x80, zeros and 64-bit message length using
     /// big-endian byte order.
     #[inline]
@@ -395,6 +410,29 @@ impl<BS: ArraySize> BlockBuffer<BS, Eager> {
 }
 
 impl<BS: ArraySize> BlockBuffer<BS, Lazy> {
+    /// Return mutable reference to the whole internal block, e.g. to use it
+    /// as a staging area for squeezed XOF output.
+    ///
+    /// Unfilled bytes of the block are zeroed and cursor position is set
+    /// to block size, so data written into the block is returned by
+    /// [`get_data`][Self::get_data]. Use [`reset`][Self::reset] to discard it.
+    ///
+    /// This method is not available for eager buffers since they use the last
+    /// byte of the block to store cursor position.
+    #[inline]
+    pub fn staging_block_mut(&mut self) -> &mut Array<u8, BS> {
+        let pos = self.get_pos();
+        // SAFETY: bytes in the range of `0..pos` are already initialized and
+        // we initialize the remaining bytes, so the whole block is properly
+        // initialized. Block size always satisfies the lazy buffer invariant.
+        unsafe {
+            let buf_ptr = self.buffer.as_mut_ptr().cast::<u8>().add(pos);
+            ptr::write_bytes(buf_ptr, 0, BS::USIZE - pos);
+            self.set_pos_unchecked(BS::USIZE);
+            self.buffer.assume_init_mut()
+        }
+    }
+
     /// Serialize buffer into a byte array.
     #[inline]
     pub fn serialize(&self) -> Array<u8, Add1<BS>>
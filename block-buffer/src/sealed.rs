@@ -22,6 +22,10 @@ pub trait Sealed {
 
     /// Split input data into slice of blocks and tail.
     fn split_blocks<N: ArraySize>(data: &[u8]) -> (&[Array<u8, N>], &[u8]);
+
+    /// Number of blocks processed when `data_len` bytes are added
+    /// to a buffer with cursor position `pos`.
+    fn blocks_for(pos: usize, data_len: usize, block_size: usize) -> usize;
 }
 
 impl Sealed for super::Eager {
@@ -68,6 +72,12 @@ impl Sealed for super::Eager {
             )
         }
     }
+
+    #[inline(always)]
+    fn blocks_for(pos: usize, data_len: usize, block_size: usize) -> usize {
+        // Equivalent to `(pos + data_len) / block_size` without overflow
+        data_len / block_size + (pos + data_len % block_size) / block_size
+    }
 }
 
 impl Sealed for super::Lazy {
@@ -110,4 +120,14 @@ impl Sealed for super::Lazy {
             )
         }
     }
+
+    #[inline(always)]
+    fn blocks_for(pos: usize, data_len: usize, block_size: usize) -> usize {
+        // Lazy buffers keep the last block, so this is equivalent
+        // to `(pos + data_len - 1) / block_size` without overflow
+        match data_len.checked_sub(1) {
+            Some(n) => n / block_size + (pos + n % block_size) / block_size,
+            None => 0,
+        }
+    }
 }
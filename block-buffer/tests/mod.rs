@@ -163,6 +163,76 @@ fn test_eager_paddings() {
     assert_eq!(out, hex!("42ff1011"));
 }
 
+#[test]
+fn test_lazy_paddings() {
+    // Exactly full buffer: the buffered block is compressed before padding
+    let mut buf = LazyBuffer::<U4>::new(b"0123");
+    let mut out = Vec::<u8>::new();
+    buf.digest_pad(0x80, &hex!("1011"), |block| out.extend(block));
+    assert_eq!(out, hex!("30313233 80001011"));
+    assert_eq!(buf.get_pos(), 0);
+
+    let mut buf = LazyBuffer::<U4>::new(b"0123");
+    let mut out = Vec::<u8>::new();
+    buf.digest_pad(0x80, &hex!("10111213"), |block| out.extend(block));
+    assert_eq!(out, hex!("30313233 80000000 10111213"));
+    assert_eq!(buf.get_pos(), 0);
+
+    // Partially full buffer behaves like the eager one
+    let mut buf = LazyBuffer::<U4>::new(&[0x42]);
+    let mut out = Vec::<u8>::new();
+    buf.digest_pad(0xff, &hex!("1011"), |block| out.extend(block));
+    assert_eq!(out, hex!("42ff1011"));
+
+    let mut buf = LazyBuffer::<U4>::new(&[0x42]);
+    let mut out = Vec::<u8>::new();
+    buf.digest_pad(0xff, &hex!("101112"), |block| out.extend(block));
+    assert_eq!(out, hex!("42ff000000101112"));
+    assert_eq!(buf.get_pos(), 0);
+}
+
+#[test]
+fn test_lazy_staging_block() {
+    let mut buf = LazyBuffer::<U4>::new(&[0x42]);
+    assert_eq!(buf.staging_block_mut(), &[0x42, 0, 0, 0]);
+    assert_eq!(buf.get_pos(), 4);
+
+    buf.staging_block_mut().copy_from_slice(b"abcd");
+    assert_eq!(buf.get_data(), b"abcd");
+    assert_eq!(buf.remaining(), 0);
+
+    buf.reset();
+    assert_eq!(buf.get_data(), b"");
+    assert_eq!(buf.staging_block_mut(), &[0; 4]);
+}
+
+#[test]
+fn test_blocks_for() {
+    for pos in 0..4 {
+        for n in 0..20 {
+            let mut buf = EagerBuffer::<U4>::new(&[0x42; 4][..pos]);
+            let expected = buf.blocks_for(n);
+            let mut blocks = 0;
+            buf.digest_blocks(&[0x13; 20][..n], |b| blocks += b.len());
+            assert_eq!(blocks, expected, "eager: pos={pos} n={n}");
+        }
+    }
+    for pos in 0..=4 {
+        for n in 0..20 {
+            let mut buf = LazyBuffer::<U4>::new(&[0x42; 4][..pos]);
+            let expected = buf.blocks_for(n);
+            let mut blocks = 0;
+            buf.digest_blocks(&[0x13; 20][..n], |b| blocks += b.len());
+            assert_eq!(blocks, expected, "lazy: pos={pos} n={n}");
+        }
+    }
+
+    let buf = EagerBuffer::<U4>::new(&[0; 3]);
+    assert_eq!(buf.blocks_for(usize::MAX), usize::MAX / 4 + 1);
+    let buf = LazyBuffer::<U4>::new(&[0; 4]);
+    assert_eq!(buf.blocks_for(usize::MAX), usize::MAX / 4 + 1);
+}
+
 #[test]
 fn test_try_new() {
     assert!(EagerBuffer::<U4>::try_new(&[0; 3]).is_ok());
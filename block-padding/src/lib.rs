@@ -316,7 +316,21 @@ impl RawPadding for AnsiX923 {
 /// assert_eq!(&block[..], b"test\x80\x00\x00\x00");
 /// let res = Iso7816::unpad(&block).unwrap();
 /// assert_eq!(res, msg);
+///
+/// // Message bytes equal to `0x80` are preserved
+/// let block: Array::<u8, U8> = (*b"te\x80t\x80\x00\x00\x00").into();
+/// assert_eq!(Iso7816::unpad(&block).unwrap(), b"te\x80t");
+///
+/// // Non-zero bytes after the delimiter and missing delimiter are rejected
+/// let block: Array::<u8, U8> = (*b"test\x80\x00\x01\x00").into();
+/// assert!(Iso7816::unpad(&block).is_err());
+/// let block: Array::<u8, U8> = (*b"test\x00\x00\x00\x00").into();
+/// assert!(Iso7816::unpad(&block).is_err());
 /// ```
+///
+/// Unpadding is strict: the last non-zero byte of the block must be the `0x80`
+/// delimiter and all bytes after it must be zero. Bytes before the delimiter
+/// belong to the message and are not inspected.
 #[derive(Clone, Copy, Debug)]
 pub struct Iso7816;
 
@@ -135,6 +135,42 @@ impl CmovEq for u128 {
     }
 }
 
+impl<const N: usize> Cmov for [u64; N] {
+    #[inline]
+    fn cmovnz(&mut self, value: &Self, condition: Condition) {
+        for (a, b) in self.iter_mut().zip(value.iter()) {
+            a.cmovnz(b, condition);
+        }
+    }
+
+    #[inline]
+    fn cmovz(&mut self, value: &Self, condition: Condition) {
+        for (a, b) in self.iter_mut().zip(value.iter()) {
+            a.cmovz(b, condition);
+        }
+    }
+}
+
+impl<const N: usize> CmovEq for [u64; N] {
+    #[inline]
+    fn cmovne(&self, rhs: &Self, input: Condition, output: &mut Condition) {
+        let mut tmp = 1u8;
+        for (a, b) in self.iter().zip(rhs.iter()) {
+            a.cmovne(b, 0, &mut tmp);
+        }
+        tmp.cmoveq(&0, input, output);
+    }
+
+    #[inline]
+    fn cmoveq(&self, rhs: &Self, input: Condition, output: &mut Condition) {
+        let mut tmp = 1u8;
+        for (a, b) in self.iter().zip(rhs.iter()) {
+            a.cmovne(b, 0, &mut tmp);
+        }
+        tmp.cmoveq(&1, input, output);
+    }
+}
+
 impl<T: CmovEq> CmovEq for [T] {
     fn cmoveq(&self, rhs: &Self, input: Condition, output: &mut Condition) {
         let mut tmp = 1u8;
@@ -156,3 +192,27 @@ impl<T: CmovEq> CmovEq for [T] {
         }
     }
 }
+
+/// Constant-time table lookup.
+///
+/// Returns `table[index]`, reading every entry of `table` and selecting the
+/// requested one using [`Cmov`], so the memory access pattern is independent
+/// of `index`.
+///
+/// If `index` is out of bounds an all-zero entry is returned.
+///
+/// # Panics
+/// If `table` has more than 256 entries.
+pub fn ct_lookup<const N: usize>(table: &[[u8; N]], index: u8) -> [u8; N] {
+    assert!(table.len() <= 256, "table has more than 256 entries");
+
+    let mut out = [0u8; N];
+    for (i, entry) in table.iter().enumerate() {
+        let mut cond = 0u8;
+        (i as u8).cmoveq(&index, 1, &mut cond);
+        for (dst, src) in out.iter_mut().zip(entry.iter()) {
+            dst.cmovnz(src, cond);
+        }
+    }
+    out
+}
@@ -3,12 +3,27 @@
 //! This implementation is based on portable bitwise arithmetic but cannot
 //! guarantee that the resulting generated assembly is free of branch
 //! instructions.
+//!
+//! With the `portable-hardened` feature enabled, inputs and outputs of the
+//! underlying `u64` operations are passed through [`core::hint::black_box`]
+//! to make it harder for the compiler to reintroduce branches. This is still
+//! not a guarantee.
 
 // TODO(tarcieri): more optimized implementation for small integers
 
 use crate::{Cmov, CmovEq, Condition};
 use core::mem::size_of;
 
+#[cfg(feature = "portable-hardened")]
+use core::hint::black_box;
+
+/// No-op replacement for [`core::hint::black_box`].
+#[cfg(not(feature = "portable-hardened"))]
+#[inline(always)]
+fn black_box<T>(x: T) -> T {
+    x
+}
+
 impl Cmov for u16 {
     #[inline]
     fn cmovnz(&mut self, value: &Self, condition: Condition) {
@@ -68,14 +83,14 @@ impl CmovEq for u32 {
 impl Cmov for u64 {
     #[inline]
     fn cmovnz(&mut self, value: &Self, condition: Condition) {
-        let mask = is_non_zero(condition).wrapping_sub(1);
-        *self = (*self & mask) | (*value & !mask);
+        let mask = black_box(is_non_zero(black_box(condition)).wrapping_sub(1));
+        *self = black_box((black_box(*self) & mask) | (black_box(*value) & !mask));
     }
 
     #[inline]
     fn cmovz(&mut self, value: &Self, condition: Condition) {
-        let mask = (1 ^ is_non_zero(condition)).wrapping_sub(1);
-        *self = (*self & mask) | (*value & !mask);
+        let mask = black_box((1 ^ is_non_zero(black_box(condition))).wrapping_sub(1));
+        *self = black_box((black_box(*self) & mask) | (black_box(*value) & !mask));
     }
 }
 
@@ -333,6 +333,69 @@ mod u128 {
     }
 }
 
+mod u64_arrays {
+    use cmov::{Cmov, CmovEq};
+
+    pub const LIMBS_A: [u64; 4] = [
+        0x1111_1111_1111_1111,
+        0x2222_2222_2222_2222,
+        0x3333_3333_3333_3333,
+        0x4444_4444_4444_4444,
+    ];
+    pub const LIMBS_B: [u64; 4] = [
+        0x1111_1111_1111_1111,
+        0x2222_2222_2222_2222,
+        0x3333_3333_3333_3333,
+        0x5555_5555_5555_5555,
+    ];
+
+    #[test]
+    fn cmovz_works() {
+        let mut n = LIMBS_A;
+
+        for cond in 1..0xFF {
+            n.cmovz(&LIMBS_B, cond);
+            assert_eq!(n, LIMBS_A);
+        }
+
+        n.cmovz(&LIMBS_B, 0);
+        assert_eq!(n, LIMBS_B);
+    }
+
+    #[test]
+    fn cmovnz_works() {
+        let mut n = LIMBS_A;
+        n.cmovnz(&LIMBS_B, 0);
+        assert_eq!(n, LIMBS_A);
+
+        for cond in 1..0xFF {
+            let mut n = LIMBS_A;
+            n.cmovnz(&LIMBS_B, cond);
+            assert_eq!(n, LIMBS_B);
+        }
+    }
+
+    #[test]
+    fn cmoveq_works() {
+        let mut o = 0u8;
+
+        LIMBS_A.cmoveq(&LIMBS_A, 43u8, &mut o);
+        assert_eq!(o, 43u8);
+        LIMBS_A.cmoveq(&LIMBS_B, 55u8, &mut o);
+        assert_eq!(o, 43u8);
+    }
+
+    #[test]
+    fn cmovne_works() {
+        let mut o = 0u8;
+
+        LIMBS_A.cmovne(&LIMBS_B, 55u8, &mut o);
+        assert_eq!(o, 55u8);
+        LIMBS_A.cmovne(&LIMBS_A, 12u8, &mut o);
+        assert_eq!(o, 55u8);
+    }
+}
+
 mod slices {
     use cmov::CmovEq;
 
@@ -370,3 +433,33 @@ mod slices {
         assert_eq!(o, 45);
     }
 }
+
+mod ct_lookup {
+    use cmov::ct_lookup;
+
+    #[test]
+    fn matches_direct_indexing() {
+        let mut table = [[0u8; 4]; 256];
+        for (i, entry) in table.iter_mut().enumerate() {
+            *entry = [i as u8, !(i as u8), (i as u8).wrapping_mul(3), 0x5A];
+        }
+
+        for index in [0u8, 1, 2, 17, 128, 254, 255] {
+            assert_eq!(ct_lookup(&table, index), table[index as usize]);
+        }
+    }
+
+    #[test]
+    fn out_of_bounds_index() {
+        let table = [[1u8, 2], [3, 4], [5, 6]];
+        assert_eq!(ct_lookup(&table, 2), [5, 6]);
+        assert_eq!(ct_lookup(&table, 3), [0, 0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn oversized_table() {
+        let table = [[0u8; 1]; 257];
+        ct_lookup(&table, 0);
+    }
+}
@@ -0,0 +1,41 @@
+//! [`Collection`] support for `hybrid-array`.
+
+use crate::Collection;
+use hybrid_array::{Array, ArraySize};
+
+/// Fixed-capacity buffer backed by [`Array<T, U>`].
+///
+/// Behaves like [`CappedBuf`](crate::CappedBuf), but is sized with an
+/// [`ArraySize`] and can be converted into the filled array with
+/// [`CollectArray::into_array`].
+#[derive(Clone)]
+pub struct CollectArray<T, U: ArraySize> {
+    buf: Array<T, U>,
+    len: usize,
+}
+
+impl<T, U: ArraySize> CollectArray<T, U> {
+    /// Consume the buffer and return the underlying array if it is full.
+    ///
+    /// Returns `self` back if the buffer is not filled to capacity.
+    pub fn into_array(self) -> Result<Array<T, U>, Self> {
+        if self.len == U::USIZE {
+            Ok(self.buf)
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl<T: Default, U: ArraySize> Default for CollectArray<T, U> {
+    fn default() -> Self {
+        Self {
+            buf: Default::default(),
+            len: 0,
+        }
+    }
+}
+
+impl_capped_collection!([U: ArraySize] CollectArray<U>);
+
+impl<T: Default, U: ArraySize> Collection<T> for CollectArray<T, U> {}
@@ -12,6 +12,91 @@
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+/// Implement the [`Collection`] component traits for a fixed-capacity buffer
+/// type with `buf` storage and a `len` cursor counting initialized elements.
+macro_rules! impl_capped_collection {
+    ([$($params:tt)*] $name:ident<$arg:ident>) => {
+        impl<T: core::fmt::Debug, $($params)*> core::fmt::Debug for $name<T, $arg> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.debug_tuple(stringify!($name))
+                    .field(&self.as_ref())
+                    .finish()
+            }
+        }
+
+        impl<T, $($params)*> AsRef<[T]> for $name<T, $arg> {
+            fn as_ref(&self) -> &[T] {
+                &self.buf[..self.len]
+            }
+        }
+
+        impl<T, $($params)*> AsMut<[T]> for $name<T, $arg> {
+            fn as_mut(&mut self) -> &mut [T] {
+                &mut self.buf[..self.len]
+            }
+        }
+
+        impl<T, $($params)*> $crate::Length for $name<T, $arg> {
+            fn len(&self) -> usize {
+                self.len
+            }
+        }
+
+        impl<T, $($params)*> $crate::Capacity for $name<T, $arg> {
+            fn capacity(&self) -> usize {
+                self.buf.len()
+            }
+        }
+
+        impl<T, $($params)*> $crate::Truncate for $name<T, $arg> {
+            /// Unlike `Vec::truncate`, elements past `len` are not dropped
+            /// until they are overwritten or the buffer itself is dropped.
+            fn truncate(&mut self, len: usize) {
+                if len < self.len {
+                    self.len = len;
+                }
+            }
+        }
+
+        impl<A, $($params)*> $crate::TryExtend<A> for $name<A, $arg> {
+            type Error = $crate::CapacityError;
+
+            /// Elements which fit into the buffer are pushed before an error is
+            /// returned for the first element exceeding its capacity.
+            fn try_extend<T>(&mut self, iter: T) -> Result<(), $crate::CapacityError>
+            where
+                T: IntoIterator<Item = A>,
+            {
+                for item in iter {
+                    $crate::TryPush::try_push(self, item).map_err(|_| $crate::CapacityError)?;
+                }
+                Ok(())
+            }
+        }
+
+        impl<T, $($params)*> $crate::TryPush<T> for $name<T, $arg> {
+            fn try_push(&mut self, item: T) -> Result<(), T> {
+                match self.buf.get_mut(self.len) {
+                    Some(slot) => {
+                        *slot = item;
+                        self.len += 1;
+                        Ok(())
+                    }
+                    None => Err(item),
+                }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "hybrid-array")]
+mod array;
+
+#[cfg(feature = "hybrid-array")]
+pub use {array::CollectArray, hybrid_array};
+
+use core::fmt;
+
 /// Collection types implement all of the traits in this crate.
 pub trait Collection<T>:
     AsRef<[T]> + AsMut<[T]> + Default + Length + Truncate + TryExtend<T> + TryPush<T>
@@ -29,6 +114,19 @@ pub trait Length {
     }
 }
 
+/// Obtain the capacity of a collection.
+pub trait Capacity: Length {
+    /// Get the number of elements this collection can hold without
+    /// reallocating or, for fixed-size collections, at all.
+    fn capacity(&self) -> usize;
+
+    /// Get the number of elements which can be added before the capacity is
+    /// reached.
+    fn remaining_capacity(&self) -> usize {
+        self.capacity() - self.len()
+    }
+}
+
 /// Truncate the collection to the provided length.
 pub trait Truncate {
     /// Truncate this buffer to the given number of elements.
@@ -117,9 +215,47 @@ where
     }
 }
 
+/// Error returned when the capacity of a fixed-size collection is exceeded.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct CapacityError;
+
+impl fmt::Display for CapacityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("collection capacity exceeded")
+    }
+}
+
+/// Dependency-free fixed-capacity buffer backed by `[T; N]`.
+///
+/// Tracks the number of initialized elements with a length cursor and
+/// implements [`Collection`].
+#[derive(Copy, Clone)]
+pub struct CappedBuf<T, const N: usize> {
+    buf: [T; N],
+    len: usize,
+}
+
+impl<T, const N: usize> CappedBuf<T, N> {
+    /// Total number of elements the buffer can hold.
+    pub const CAPACITY: usize = N;
+}
+
+impl<T: Copy + Default, const N: usize> Default for CappedBuf<T, N> {
+    fn default() -> Self {
+        Self {
+            buf: [T::default(); N],
+            len: 0,
+        }
+    }
+}
+
+impl_capped_collection!([const N: usize] CappedBuf<N>);
+
+impl<T: Copy + Default, const N: usize> Collection<T> for CappedBuf<T, N> {}
+
 #[cfg(feature = "alloc")]
 mod vec_impls {
-    use super::{Length, Truncate, TryExtend};
+    use super::{Capacity, Length, Truncate, TryExtend};
     use alloc::vec::Vec;
     use core::convert::Infallible;
 
@@ -129,6 +265,12 @@ mod vec_impls {
         }
     }
 
+    impl<T> Capacity for Vec<T> {
+        fn capacity(&self) -> usize {
+            Vec::capacity(self)
+        }
+    }
+
     impl<T> Truncate for Vec<T> {
         fn truncate(&mut self, len: usize) {
             Vec::truncate(self, len);
@@ -0,0 +1,28 @@
+use collectable::{Capacity, CappedBuf, TryPush};
+
+#[test]
+fn capped_buf_remaining_capacity() {
+    let mut buf = CappedBuf::<u8, 3>::default();
+    assert_eq!(buf.capacity(), 3);
+    assert_eq!(buf.remaining_capacity(), 3);
+
+    for i in 0..3 {
+        buf.try_push(i).unwrap();
+        assert_eq!(buf.remaining_capacity(), 2 - i as usize);
+    }
+    assert_eq!(buf.try_push(3), Err(3));
+    assert_eq!(buf.remaining_capacity(), 0);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn vec_remaining_capacity() {
+    let mut v = Vec::<u8>::with_capacity(4);
+    let cap = Capacity::capacity(&v);
+    assert!(cap >= 4);
+    assert_eq!(v.remaining_capacity(), cap);
+
+    v.push(1);
+    v.push(2);
+    assert_eq!(v.remaining_capacity(), cap - 2);
+}
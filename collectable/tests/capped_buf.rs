@@ -0,0 +1,48 @@
+use collectable::{
+    CapacityError, CappedBuf, Length, Truncate, TryExtend, TryFromIterator, TryPush,
+};
+
+#[test]
+fn push_past_capacity() {
+    let mut buf = CappedBuf::<u8, 3>::default();
+    assert!(buf.is_empty());
+
+    for i in 0..3 {
+        assert_eq!(buf.try_push(i), Ok(()));
+    }
+    assert_eq!(buf.len(), CappedBuf::<u8, 3>::CAPACITY);
+    assert_eq!(buf.try_push(42), Err(42));
+    assert_eq!(buf.as_ref(), [0, 1, 2]);
+}
+
+#[test]
+fn extend_past_capacity() {
+    let mut buf = CappedBuf::<u8, 4>::default();
+    assert_eq!(buf.try_extend_from_slice(&[1, 2]), Ok(()));
+    assert_eq!(buf.try_extend(3..10), Err(CapacityError));
+    assert_eq!(buf.as_ref(), [1, 2, 3, 4]);
+}
+
+#[test]
+fn truncate() {
+    let mut buf = CappedBuf::<u8, 4>::try_from_iter(1..=4).unwrap();
+    buf.truncate(8);
+    assert_eq!(buf.as_ref(), [1, 2, 3, 4]);
+
+    buf.truncate(2);
+    assert_eq!(buf.as_ref(), [1, 2]);
+    buf.as_mut()[1] = 5;
+
+    assert_eq!(buf.try_push(6), Ok(()));
+    assert_eq!(buf.as_ref(), [1, 5, 6]);
+
+    buf.truncate(0);
+    assert!(buf.is_empty());
+}
+
+#[test]
+fn debug_skips_truncated() {
+    let mut buf = CappedBuf::<u8, 4>::try_from_iter(1..=4).unwrap();
+    buf.truncate(2);
+    assert_eq!(format!("{:?}", buf), "CappedBuf([1, 2])");
+}
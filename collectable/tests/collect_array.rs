@@ -0,0 +1,52 @@
+#![cfg(feature = "hybrid-array")]
+
+use collectable::{
+    hybrid_array::{typenum::U4, Array},
+    Capacity, CapacityError, CollectArray, Length, Truncate, TryExtend, TryFromIterator, TryPush,
+};
+
+#[test]
+fn push_past_capacity() {
+    let mut buf = CollectArray::<u8, U4>::default();
+    assert!(buf.is_empty());
+    assert_eq!(buf.capacity(), 4);
+
+    for i in 0..4 {
+        assert_eq!(buf.try_push(i), Ok(()));
+    }
+    assert_eq!(buf.len(), 4);
+    assert_eq!(buf.remaining_capacity(), 0);
+    assert_eq!(buf.try_push(42), Err(42));
+    assert_eq!(buf.as_ref(), [0, 1, 2, 3]);
+}
+
+#[test]
+fn extend_past_capacity() {
+    let mut buf = CollectArray::<u8, U4>::default();
+    assert_eq!(buf.try_extend_from_slice(&[1, 2]), Ok(()));
+    assert_eq!(buf.try_extend(3..10), Err(CapacityError));
+    assert_eq!(buf.as_ref(), [1, 2, 3, 4]);
+}
+
+#[test]
+fn truncate() {
+    let mut buf = CollectArray::<u8, U4>::try_from_iter(1..=4).unwrap();
+    buf.truncate(8);
+    assert_eq!(buf.as_ref(), [1, 2, 3, 4]);
+
+    buf.truncate(2);
+    assert_eq!(buf.as_ref(), [1, 2]);
+
+    assert_eq!(buf.try_push(5), Ok(()));
+    assert_eq!(buf.as_ref(), [1, 2, 5]);
+}
+
+#[test]
+fn into_array() {
+    let buf = CollectArray::<u8, U4>::try_from_iter(1..=3).unwrap();
+    let mut buf = buf.into_array().unwrap_err();
+    assert_eq!(buf.as_ref(), [1, 2, 3]);
+
+    assert_eq!(buf.try_push(4), Ok(()));
+    assert_eq!(buf.into_array().unwrap(), Array([1, 2, 3, 4]));
+}
@@ -27,7 +27,7 @@ macro_rules! __unless_target_features {
 macro_rules! __detect_target_features {
     ($($tf:tt),+) => {{
         let hwcaps = $crate::aarch64::getauxval_hwcap();
-        $($crate::check!(hwcaps, $tf) & )+ true
+        [$($crate::check!(hwcaps, $tf)),+]
     }};
 }
 
@@ -43,7 +43,17 @@ pub fn getauxval_hwcap() -> u64 {
 #[doc(hidden)]
 macro_rules! __detect_target_features {
     ($($tf:tt),+) => {{
-        $($crate::check!($tf) & )+ true
+        [$($crate::check!($tf)),+]
+    }};
+}
+
+// Windows runtime detection of target CPU features using `IsProcessorFeaturePresent`.
+#[cfg(target_os = "windows")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __detect_target_features {
+    ($($tf:tt),+) => {{
+        [$($crate::check!($tf)),+]
     }};
 }
 
@@ -67,6 +77,7 @@ macro_rules! __expand_check_macro {
 #[cfg(any(target_os = "linux", target_os = "android"))]
 __expand_check_macro! {
     ("aes",    AES),    // Enable AES support.
+    ("crc",    CRC),    // Enable CRC32 support.
     ("dit",    DIT),    // Enable DIT support.
     ("sha2",   SHA2),   // Enable SHA1 and SHA256 support.
     ("sha3",   SHA3),   // Enable SHA512 and SHA3 support.
@@ -85,6 +96,7 @@ pub mod hwcaps {
     use libc::c_ulong;
 
     pub const AES: c_ulong = libc::HWCAP_AES | libc::HWCAP_PMULL;
+    pub const CRC: c_ulong = libc::HWCAP_CRC32;
     pub const DIT: c_ulong = libc::HWCAP_DIT;
     pub const SHA2: c_ulong = libc::HWCAP_SHA2;
     pub const SHA3: c_ulong = libc::HWCAP_SHA3 | libc::HWCAP_SHA512;
@@ -109,6 +121,10 @@ macro_rules! check {
     ("aes") => {
         true
     };
+    ("crc") => {
+        // CRC32 instructions are optional on ARMv8.0 (e.g. Apple A7-A9)
+        unsafe { $crate::aarch64::sysctlbyname(b"hw.optional.armv8_crc32\0") }
+    };
     ("dit") => {
         // https://developer.apple.com/documentation/xcode/writing-arm64-code-for-apple-platforms#Enable-DIT-for-constant-time-cryptographic-operations
         unsafe {
@@ -156,12 +172,72 @@ pub unsafe fn sysctlbyname(name: &[u8]) -> bool {
     value != 0
 }
 
+// Windows `check!` macro.
+//
+// NOTE: `IsProcessorFeaturePresent` reports AES, PMULL, SHA1 and SHA256
+// support as a single "crypto" feature. Features without a corresponding
+// `PF_*` constant are conservatively reported as unavailable.
+#[cfg(target_os = "windows")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! check {
+    ("aes") => {
+        $crate::aarch64::is_processor_feature_present(
+            $crate::aarch64::PF_ARM_V8_CRYPTO_INSTRUCTIONS_AVAILABLE,
+        )
+    };
+    ("crc") => {
+        $crate::aarch64::is_processor_feature_present(
+            $crate::aarch64::PF_ARM_V8_CRC32_INSTRUCTIONS_AVAILABLE,
+        )
+    };
+    ("dit") => {
+        false
+    };
+    ("sha2") => {
+        $crate::aarch64::is_processor_feature_present(
+            $crate::aarch64::PF_ARM_V8_CRYPTO_INSTRUCTIONS_AVAILABLE,
+        )
+    };
+    ("sha3") => {
+        false
+    };
+    ("sm4") => {
+        false
+    };
+}
+
+/// Windows processor feature: AES, PMULL, SHA1 and SHA256 instructions.
+#[cfg(target_os = "windows")]
+pub const PF_ARM_V8_CRYPTO_INSTRUCTIONS_AVAILABLE: u32 = 30;
+
+/// Windows processor feature: CRC32 instructions.
+#[cfg(target_os = "windows")]
+pub const PF_ARM_V8_CRC32_INSTRUCTIONS_AVAILABLE: u32 = 31;
+
+#[cfg(target_os = "windows")]
+#[link(name = "kernel32")]
+extern "system" {
+    fn IsProcessorFeaturePresent(processor_feature: u32) -> i32;
+}
+
+/// Windows helper function for calling `IsProcessorFeaturePresent`.
+#[cfg(target_os = "windows")]
+pub fn is_processor_feature_present(processor_feature: u32) -> bool {
+    unsafe { IsProcessorFeaturePresent(processor_feature) != 0 }
+}
+
 // On other targets, runtime CPU feature detection is unavailable
-#[cfg(not(any(target_vendor = "apple", target_os = "linux", target_os = "android",)))]
+#[cfg(not(any(
+    target_vendor = "apple",
+    target_os = "linux",
+    target_os = "android",
+    target_os = "windows",
+)))]
 #[macro_export]
 #[doc(hidden)]
 macro_rules! __detect_target_features {
     ($($tf:tt),+) => {
-        false
+        [false; [$($tf),+].len()]
     };
 }
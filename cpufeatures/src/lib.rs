@@ -10,11 +10,12 @@
 //!
 //! ## `aarch64`
 //!
-//! Linux, iOS, and macOS/ARM only (ARM64 does not support OS-independent feature detection)
+//! Linux, iOS, macOS/ARM, and Windows only (ARM64 does not support OS-independent feature detection)
 //!
 //! Target features:
 //!
 //! - `aes`*
+//! - `crc`
 //! - `sha2`*
 //! - `sha3`*
 //!
@@ -63,6 +64,7 @@
 //! - `bmi1`
 //! - `bmi2`
 //! - `fma`,
+//! - `gfni`
 //! - `mmx`
 //! - `pclmulqdq`
 //! - `popcnt`
@@ -76,6 +78,11 @@
 //! - `sse4.1`
 //! - `sse4.2`
 //! - `ssse3`
+//! - `vaes`
+//!
+//! Note that `vaes` only requires the 256-bit (`ymm`) register state to be
+//! enabled. Use `cpufeatures::new!(mod_name, "vaes", "avx512f")` to check for
+//! availability of 512-bit VAES instructions.
 //!
 //! If you would like detection support for a target feature which is not on
 //! this list, please [open a GitHub issue][gh].
@@ -117,6 +124,34 @@
 //! After first call macro caches result and returns it in subsequent
 //! calls, thus runtime overhead for them is minimal.
 //!
+//! Availability of individual target features is cached as well and can be
+//! queried from the token using their index in the list passed to the macro:
+//! ```
+//! # #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+//! # {
+//! cpufeatures::new!(cpuid_aes_sha, "aes", "sha");
+//!
+//! let token = cpuid_aes_sha::init();
+//! if token.get_feature(0) {
+//!     println!("CPU supports AES extensions");
+//! }
+//! # }
+//! ```
+//!
+//! The [`new_any!`] macro creates a module with the same API, but its `get`
+//! method returns `true` if *any* of the listed target features is available,
+//! which is useful for tiered dispatch:
+//! ```
+//! # #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+//! # {
+//! cpufeatures::new_any!(cpuid_aes_any, "vaes", "aes");
+//!
+//! if cpuid_aes_any::get() {
+//!     println!("CPU supports either VAES or AES extensions");
+//! }
+//! # }
+//! ```
+//!
 //! [RFC 2725]: https://github.com/rust-lang/rfcs/pull/2725
 //! [gh]: https://github.com/RustCrypto/utils/issues/new?title=cpufeatures:%20requesting%20support%20for%20CHANGEME%20target%20feature
 
@@ -155,11 +190,60 @@ compile_error!("This crate works only on `aarch64`, `loongarch64`, `x86`, and `x
 #[macro_export]
 macro_rules! new {
     ($mod_name:ident, $($tf:tt),+ $(,)?) => {
+        $crate::__new_module!($mod_name, all, $($tf),+);
+    };
+}
+
+/// Create module with CPU feature detection code which checks that *any* of
+/// the listed target features is available.
+///
+/// The generated module has the same API as the one created by [`new!`].
+#[macro_export]
+macro_rules! new_any {
+    ($mod_name:ident, $($tf:tt),+ $(,)?) => {
+        $crate::__new_module!($mod_name, any, $($tf),+);
+    };
+}
+
+/// Combine detected target features stored in the `$mask` bitmask.
+///
+/// Evaluates to `true` without evaluating `$mask` if the combination
+/// is satisfied by target features enabled at compile time.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __combine_target_features {
+    (all, $($tf:tt),+ => $mask:expr) => {
+        $crate::__unless_target_features! {
+            $($tf),+ => {
+                ($mask) & ALL == ALL
+            }
+        }
+    };
+    (any, $($tf:tt),+ => $mask:expr) => {
+        ($($crate::__unless_target_features! {
+            $tf => {
+                ($mask) & ALL != 0
+            }
+        })||+)
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __new_module {
+    ($mod_name:ident, $mode:ident, $($tf:tt),+) => {
         mod $mod_name {
-            use core::sync::atomic::{AtomicU8, Ordering::Relaxed};
+            use core::sync::atomic::{AtomicU32, Ordering::Relaxed};
 
-            const UNINIT: u8 = u8::max_value();
-            static STORAGE: AtomicU8 = AtomicU8::new(UNINIT);
+            const COUNT: usize = [$($tf),+].len();
+            // At most 31 target features are supported, since the highest
+            // storage bit is used to mark it as initialized.
+            const _: [(); 0] = [(); (COUNT > 31) as usize];
+            const ALL: u32 = (1 << COUNT) - 1;
+            const INIT: u32 = 1 << 31;
+
+            // Bit `i` is set if the `i`-th target feature was detected
+            static STORAGE: AtomicU32 = AtomicU32::new(0);
 
             /// Initialization token
             #[derive(Copy, Clone, Debug)]
@@ -169,11 +253,43 @@ macro_rules! new {
                 /// Get initialized value
                 #[inline(always)]
                 pub fn get(&self) -> bool {
-                    $crate::__unless_target_features! {
-                        $($tf),+ => {
-                            STORAGE.load(Relaxed) == 1
-                        }
-                    }
+                    $crate::__combine_target_features!($mode, $($tf),+ => STORAGE.load(Relaxed))
+                }
+
+                /// Get initialized value for the `index`-th target feature
+                /// passed to the macro.
+                ///
+                /// # Panics
+                /// If `index` is bigger or equal to the number of target features.
+                #[inline]
+                pub fn get_feature(&self, index: usize) -> bool {
+                    let enabled = [$($crate::__unless_target_features! { $tf => { false } }),+];
+                    enabled[index] || STORAGE.load(Relaxed) & (1 << index) != 0
+                }
+            }
+
+            #[cold]
+            #[allow(dead_code)]
+            fn init_inner() -> u32 {
+                let detected: [bool; COUNT] = $crate::__detect_target_features!($($tf),+);
+                let mut res = INIT;
+                for (i, &d) in detected.iter().enumerate() {
+                    res |= (d as u32) << i;
+                }
+                STORAGE.store(res, Relaxed);
+                res
+            }
+
+            #[inline(always)]
+            #[allow(dead_code)]
+            fn load() -> u32 {
+                // Relaxed ordering is fine, as we only have a single atomic variable.
+                let val = STORAGE.load(Relaxed);
+
+                if val & INIT == 0 {
+                    init_inner()
+                } else {
+                    val
                 }
             }
 
@@ -181,26 +297,15 @@ macro_rules! new {
             /// initializing underlying storage if needed.
             #[inline]
             pub fn init_get() -> (InitToken, bool) {
+                // Storage must be initialized even if the combination is
+                // satisfied by target features enabled at compile time, since
+                // `InitToken::get_feature` relies on it.
                 let res = $crate::__unless_target_features! {
                     $($tf),+ => {
-                        #[cold]
-                        fn init_inner() -> bool {
-                            let res = $crate::__detect_target_features!($($tf),+);
-                            STORAGE.store(res as u8, Relaxed);
-                            res
-                        }
-
-                        // Relaxed ordering is fine, as we only have a single atomic variable.
-                        let val = STORAGE.load(Relaxed);
-
-                        if val == UNINIT {
-                            init_inner()
-                        } else {
-                            val == 1
-                        }
+                        let mask = load();
+                        $crate::__combine_target_features!($mode, $($tf),+ => mask)
                     }
                 };
-
                 (InitToken(()), res)
             }
 
@@ -25,7 +25,7 @@ macro_rules! __unless_target_features {
 macro_rules! __detect_target_features {
     ($($tf:tt),+) => {{
         let hwcaps = $crate::loongarch64::getauxval_hwcap();
-        $($crate::check!(hwcaps, $tf) & )+ true
+        [$($crate::check!(hwcaps, $tf)),+]
     }};
 }
 
@@ -101,6 +101,6 @@ pub mod hwcaps {
 #[doc(hidden)]
 macro_rules! __detect_target_features {
     ($($tf:tt),+) => {
-        false
+        [false; [$($tf),+].len()]
     };
 }
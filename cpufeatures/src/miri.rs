@@ -15,6 +15,6 @@ macro_rules! __unless_target_features {
 #[doc(hidden)]
 macro_rules! __detect_target_features {
     ($($tf:tt),+) => {
-        false
+        [false; [$($tf),+].len()]
     };
 }
@@ -28,7 +28,9 @@ macro_rules! __unless_target_features {
     }};
 }
 
-/// Use CPUID to detect the presence of all supplied target features.
+/// Use CPUID to detect the presence of each supplied target feature.
+///
+/// Evaluates to an array with one `bool` per target feature.
 #[macro_export]
 #[doc(hidden)]
 macro_rules! __detect_target_features {
@@ -57,7 +59,7 @@ macro_rules! __detect_target_features {
             [cpuid(1), cpuid_count(7, 0)]
         };
 
-        $($crate::check!(cr, $tf) & )+ true
+        [$($crate::check!(cr, $tf)),+]
     }};
 }
 
@@ -144,8 +146,10 @@ __expand_check_macro! {
     ("avx512vl", "zmm", 1, ebx, 31),
     ("avx512vbmi", "zmm", 1, ecx, 1),
     ("avx512vbmi2", "zmm", 1, ecx, 6),
-    ("gfni", "zmm", 1, ecx, 8),
-    ("vaes", "zmm", 1, ecx, 9),
+    // GFNI and VAES have SSE/VEX encodings usable without AVX-512, so they
+    // only require the register state used by their narrowest encoding.
+    ("gfni", "", 1, ecx, 8),
+    ("vaes", "ymm", 1, ecx, 9, 0, ecx, 28),
     ("vpclmulqdq", "zmm", 1, ecx, 10),
     ("avx512bitalg", "zmm", 1, ecx, 12),
     ("avx512vpopcntdq", "zmm", 1, ecx, 14),
@@ -15,3 +15,14 @@ fn init_get() {
     let (token, val) = armcaps::init_get();
     assert_eq!(val, token.get());
 }
+
+#[cfg(target_os = "windows")]
+cpufeatures::new!(wincaps, "aes", "sha2", "crc");
+
+#[cfg(target_os = "windows")]
+#[test]
+fn windows_init_get() {
+    let (token, val) = wincaps::init_get();
+    assert_eq!(val, token.get());
+    assert_eq!(val, wincaps::get());
+}
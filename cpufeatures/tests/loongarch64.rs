@@ -18,3 +18,12 @@ fn init_get() {
     let (token, val) = lacaps::init_get();
     assert_eq!(val, token.get());
 }
+
+cpufeatures::new!(lasimd, "lsx", "lasx");
+
+#[test]
+fn simd_get() {
+    let (token, val) = lasimd::init_get();
+    assert_eq!(val, token.get());
+    assert_eq!(val, lasimd::get());
+}
@@ -15,3 +15,86 @@ fn init_get() {
     let (token, val) = cpuid::init_get();
     assert_eq!(val, token.get());
 }
+
+// `sse2` is part of the x86_64 baseline, so the module must report `true`
+// even on CPUs without SHA extensions.
+cpufeatures::new_any!(cpuid_any, "sha", "sse2");
+cpufeatures::new!(cpuid_sha, "sha");
+cpufeatures::new!(cpuid_sse2, "sse2");
+
+#[test]
+fn new_any() {
+    assert_eq!(cpuid_any::get(), cpuid_sha::get() || cpuid_sse2::get());
+    #[cfg(target_arch = "x86_64")]
+    assert!(cpuid_any::get());
+}
+
+#[test]
+fn new_any_init_get() {
+    let (token, val) = cpuid_any::init_get();
+    assert_eq!(val, token.get());
+}
+
+cpufeatures::new!(cpuid_gfni, "gfni");
+cpufeatures::new!(cpuid_vaes, "vaes");
+
+// Compare against raw CPUID reads to avoid relying on `is_x86_feature_detected!`
+// support for these target features on the MSRV.
+#[test]
+// CPUID intrinsics are safe to call on recent Rust versions
+#[allow(unused_unsafe)]
+fn gfni_vaes() {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::{__cpuid, __cpuid_count, _xgetbv};
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::{__cpuid, __cpuid_count, _xgetbv};
+
+    let (leaf1, leaf7) = unsafe { (__cpuid(1), __cpuid_count(7, 0)) };
+    let osxsave = leaf1.ecx & (1 << 27) != 0;
+    let ymm = osxsave && unsafe { _xgetbv(0) } & 0b110 == 0b110;
+    let avx = ymm && leaf1.ecx & (1 << 28) != 0;
+
+    if leaf7.ecx & (1 << 8) != 0 {
+        assert!(cpuid_gfni::get());
+    }
+    if avx && leaf7.ecx & (1 << 9) != 0 {
+        assert!(cpuid_vaes::get());
+    }
+}
+
+cpufeatures::new!(cpuid_multi, "sse2", "sha", "aes");
+cpufeatures::new!(cpuid_aes, "aes");
+
+#[test]
+fn get_feature() {
+    let (token, val) = cpuid_multi::init_get();
+    assert_eq!(token.get_feature(0), cpuid_sse2::get());
+    assert_eq!(token.get_feature(1), cpuid_sha::get());
+    assert_eq!(token.get_feature(2), cpuid_aes::get());
+    assert_eq!(
+        val,
+        token.get_feature(0) && token.get_feature(1) && token.get_feature(2)
+    );
+
+    let token = cpuid_any::init();
+    assert_eq!(token.get_feature(0), cpuid_sha::get());
+    assert_eq!(token.get_feature(1), cpuid_sse2::get());
+}
+
+// The compile-time enabled `sse2` comes first, so the combined value is known
+// without runtime detection, but `aes` still has to be detected.
+cpufeatures::new_any!(cpuid_any_static_first, "sse2", "aes");
+
+#[test]
+fn get_feature_any_static_first() {
+    let token = cpuid_any_static_first::init();
+    assert_eq!(token.get_feature(0), cpuid_sse2::get());
+    assert_eq!(token.get_feature(1), cpuid_aes::get());
+    assert_eq!(token.get_feature(1), std::is_x86_feature_detected!("aes"));
+}
+
+#[test]
+#[should_panic]
+fn get_feature_out_of_range() {
+    cpuid_multi::init().get_feature(3);
+}
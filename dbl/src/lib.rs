@@ -7,7 +7,7 @@
 #![forbid(unsafe_code)]
 
 use hybrid_array::typenum::{U16, U32, U8};
-use hybrid_array::Array;
+use hybrid_array::{Array, ArraySize};
 
 const C64: u64 = 0b1_1011;
 const C128: u64 = 0b1000_0111;
@@ -16,7 +16,8 @@ const C256: u64 = 0b100_0010_0101;
 /// Double and inverse double over GF(2^n).
 ///
 /// This trait is implemented for 64, 128 and 256 bit block sizes. Big-endian
-/// order is used.
+/// order is used. The 64 and 128 bit variants are also implemented for `u64`
+/// and `u128` respectively.
 pub trait Dbl {
     /// Double block. (alternatively: multiply block by x)
     ///
@@ -33,74 +34,96 @@ pub trait Dbl {
     /// `block>>1`, otherwise `(block>>1)^(1<<n)^(C>>1)`
     #[must_use]
     fn inv_dbl(self) -> Self;
+
+    /// Triple block. (alternatively: multiply block by x+1)
+    ///
+    /// Equivalent to `block.dbl() ^ block`. Used for computing masks in
+    /// constructions such as OCB.
+    #[must_use]
+    fn triple(self) -> Self;
 }
 
-impl Dbl for Array<u8, U8> {
+#[inline]
+fn xor<N: ArraySize>(mut a: Array<u8, N>, b: &Array<u8, N>) -> Array<u8, N> {
+    a.iter_mut().zip(b.iter()).for_each(|(a, b)| *a ^= b);
+    a
+}
+
+impl Dbl for u64 {
     #[inline]
     fn dbl(self) -> Self {
-        let mut val = u64::from_be_bytes(self.into());
-
-        let a = val >> 63;
-        val <<= 1;
-        val ^= a * C64;
-
-        val.to_be_bytes().into()
+        let a = self >> 63;
+        (self << 1) ^ (a * C64)
     }
 
     #[inline]
     fn inv_dbl(self) -> Self {
-        let mut val = u64::from_be_bytes(self.into());
-
-        let a = val & 1;
-        val >>= 1;
-        val ^= a * ((1 << 63) ^ (C64 >> 1));
+        let a = self & 1;
+        (self >> 1) ^ (a * ((1 << 63) ^ (C64 >> 1)))
+    }
 
-        val.to_be_bytes().into()
+    #[inline]
+    fn triple(self) -> Self {
+        self.dbl() ^ self
     }
 }
 
-impl Dbl for Array<u8, U16> {
+impl Dbl for u128 {
     #[inline]
     fn dbl(self) -> Self {
-        let mut val = [
-            u64::from_be_bytes(self[..8].try_into().unwrap()),
-            u64::from_be_bytes(self[8..].try_into().unwrap()),
-        ];
+        let a = self >> 127;
+        (self << 1) ^ (a * C128 as u128)
+    }
 
-        let b = val[1] >> 63;
-        let a = val[0] >> 63;
+    #[inline]
+    fn inv_dbl(self) -> Self {
+        let a = self & 1;
+        (self >> 1) ^ (a * ((1 << 127) ^ (C128 as u128 >> 1)))
+    }
 
-        val[0] <<= 1;
-        val[0] ^= b;
-        val[1] <<= 1;
-        val[1] ^= a * C128;
+    #[inline]
+    fn triple(self) -> Self {
+        self.dbl() ^ self
+    }
+}
 
-        let mut res = Self::default();
-        res[..8].copy_from_slice(&val[0].to_be_bytes());
-        res[8..].copy_from_slice(&val[1].to_be_bytes());
-        res
+impl Dbl for Array<u8, U8> {
+    #[inline]
+    fn dbl(self) -> Self {
+        u64::from_be_bytes(self.into()).dbl().to_be_bytes().into()
     }
 
     #[inline]
     fn inv_dbl(self) -> Self {
-        let mut val = [
-            u64::from_be_bytes(self[..8].try_into().unwrap()),
-            u64::from_be_bytes(self[8..].try_into().unwrap()),
-        ];
+        u64::from_be_bytes(self.into())
+            .inv_dbl()
+            .to_be_bytes()
+            .into()
+    }
 
-        let a = (val[0] & 1) << 63;
-        let b = val[1] & 1;
+    #[inline]
+    fn triple(self) -> Self {
+        xor(self.dbl(), &self)
+    }
+}
 
-        val[0] >>= 1;
-        val[1] >>= 1;
-        val[1] ^= a;
-        val[0] ^= b * (1 << 63);
-        val[1] ^= b * (C128 >> 1);
+impl Dbl for Array<u8, U16> {
+    #[inline]
+    fn dbl(self) -> Self {
+        u128::from_be_bytes(self.into()).dbl().to_be_bytes().into()
+    }
 
-        let mut res = Self::default();
-        res[..8].copy_from_slice(&val[0].to_be_bytes());
-        res[8..].copy_from_slice(&val[1].to_be_bytes());
-        res
+    #[inline]
+    fn inv_dbl(self) -> Self {
+        u128::from_be_bytes(self.into())
+            .inv_dbl()
+            .to_be_bytes()
+            .into()
+    }
+
+    #[inline]
+    fn triple(self) -> Self {
+        xor(self.dbl(), &self)
     }
 }
 
@@ -168,4 +191,9 @@ impl Dbl for Array<u8, U32> {
         res[24..32].copy_from_slice(&val[3].to_be_bytes());
         res
     }
+
+    #[inline]
+    fn triple(self) -> Self {
+        xor(self.dbl(), &self)
+    }
 }
@@ -0,0 +1,99 @@
+use dbl::Dbl;
+use hex_literal::hex;
+use hybrid_array::{
+    typenum::{U16, U8},
+    Array,
+};
+
+#[test]
+fn triple_64() {
+    let kats = [
+        (hex!("0000000000000001"), hex!("0000000000000003")),
+        (hex!("8000000000000000"), hex!("800000000000001b")),
+        (hex!("0123456789abcdef"), hex!("0365cfa89afc5631")),
+    ];
+    for (input, expected) in kats {
+        let block = Array::<u8, U8>::from(input);
+        assert_eq!(block.triple(), Array::from(expected));
+    }
+}
+
+#[test]
+fn triple_128() {
+    let kats = [
+        (
+            hex!("00000000000000000000000000000001"),
+            hex!("00000000000000000000000000000003"),
+        ),
+        (
+            hex!("80000000000000000000000000000000"),
+            hex!("80000000000000000000000000000087"),
+        ),
+        (
+            hex!("0123456789abcdeffedcba9876543210"),
+            hex!("0365cfa89afc56300365cfa89afc5630"),
+        ),
+        (
+            hex!("ffffffffffffffffffffffffffffffff"),
+            hex!("00000000000000000000000000000086"),
+        ),
+    ];
+    for (input, expected) in kats {
+        let block = Array::<u8, U16>::from(input);
+        assert_eq!(block.triple(), Array::from(expected));
+    }
+}
+
+#[test]
+fn dbl_64() {
+    let kats = [
+        (
+            hex!("0123456789abcdef"),
+            hex!("02468acf13579bde"),
+            hex!("8091a2b3c4d5e6fa"),
+        ),
+        (
+            hex!("8000000000000001"),
+            hex!("0000000000000019"),
+            hex!("c00000000000000d"),
+        ),
+    ];
+    for (input, dbl, inv_dbl) in kats {
+        let block = Array::<u8, U8>::from(input);
+        assert_eq!(block.dbl(), Array::from(dbl));
+        assert_eq!(block.inv_dbl(), Array::from(inv_dbl));
+
+        let val = u64::from_be_bytes(input);
+        assert_eq!(val.dbl(), u64::from_be_bytes(dbl));
+        assert_eq!(val.inv_dbl(), u64::from_be_bytes(inv_dbl));
+        assert_eq!(val.dbl().inv_dbl(), val);
+        assert_eq!(val.triple().to_be_bytes(), block.triple().0);
+    }
+}
+
+#[test]
+fn dbl_128() {
+    let kats = [
+        (
+            hex!("0123456789abcdeffedcba9876543210"),
+            hex!("02468acf13579bdffdb97530eca86420"),
+            hex!("0091a2b3c4d5e6f7ff6e5d4c3b2a1908"),
+        ),
+        (
+            hex!("80000000000000000000000000000001"),
+            hex!("00000000000000000000000000000085"),
+            hex!("c0000000000000000000000000000043"),
+        ),
+    ];
+    for (input, dbl, inv_dbl) in kats {
+        let block = Array::<u8, U16>::from(input);
+        assert_eq!(block.dbl(), Array::from(dbl));
+        assert_eq!(block.inv_dbl(), Array::from(inv_dbl));
+
+        let val = u128::from_be_bytes(input);
+        assert_eq!(val.dbl(), u128::from_be_bytes(dbl));
+        assert_eq!(val.inv_dbl(), u128::from_be_bytes(inv_dbl));
+        assert_eq!(val.dbl().inv_dbl(), val);
+        assert_eq!(val.triple().to_be_bytes(), block.triple().0);
+    }
+}
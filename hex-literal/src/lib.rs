@@ -5,7 +5,39 @@
     html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/media/6ee8e381/logo.svg"
 )]
 
-const fn next_hex_char(string: &[u8], mut pos: usize) -> Option<(u8, usize)> {
+use core::fmt;
+
+/// Error returned by [`decode_runtime`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum HexError {
+    /// Input contains an invalid ASCII character.
+    InvalidChar,
+    /// Input contains a non-ASCII character.
+    NonAscii,
+    /// Input contains an odd number of hex characters.
+    OddLength,
+    /// Output buffer is too small for the decoded data.
+    OutputTooSmall,
+}
+
+impl HexError {
+    const fn as_str(&self) -> &'static str {
+        match self {
+            Self::InvalidChar => "Encountered invalid ASCII character",
+            Self::NonAscii => "Encountered non-ASCII character",
+            Self::OddLength => "Odd number of hex characters",
+            Self::OutputTooSmall => "Output buffer is too small",
+        }
+    }
+}
+
+impl fmt::Display for HexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+const fn next_hex_char(string: &[u8], mut pos: usize) -> Result<Option<(u8, usize)>, HexError> {
     while pos < string.len() {
         let raw_val = string[pos];
         pos += 1;
@@ -14,24 +46,41 @@ const fn next_hex_char(string: &[u8], mut pos: usize) -> Option<(u8, usize)> {
             b'A'..=b'F' => raw_val - 55,
             b'a'..=b'f' => raw_val - 87,
             b' ' | b'\r' | b'\n' | b'\t' => continue,
-            0..=127 => panic!("Encountered invalid ASCII character"),
-            _ => panic!("Encountered non-ASCII character"),
+            0..=127 => return Err(HexError::InvalidChar),
+            _ => return Err(HexError::NonAscii),
         };
-        return Some((val, pos));
+        return Ok(Some((val, pos)));
     }
-    None
+    Ok(None)
 }
 
-const fn next_byte(string: &[u8], pos: usize) -> Option<(u8, usize)> {
+const fn next_byte(string: &[u8], pos: usize) -> Result<Option<(u8, usize)>, HexError> {
     let (half1, pos) = match next_hex_char(string, pos) {
-        Some(v) => v,
-        None => return None,
+        Ok(Some(v)) => v,
+        res => return res,
     };
     let (half2, pos) = match next_hex_char(string, pos) {
-        Some(v) => v,
-        None => panic!("Odd number of hex characters"),
+        Ok(Some(v)) => v,
+        Ok(None) => return Err(HexError::OddLength),
+        Err(err) => return Err(err),
     };
-    Some(((half1 << 4) + half2, pos))
+    Ok(Some(((half1 << 4) + half2, pos)))
+}
+
+/// Decode hex string `s` into `out` at runtime.
+///
+/// Accepts the same input as the [`hex!`] macro and returns number of bytes
+/// written into `out`.
+pub fn decode_runtime(s: &str, out: &mut [u8]) -> Result<usize, HexError> {
+    let string = s.as_bytes();
+    let mut pos = 0;
+    let mut len = 0;
+    while let Some((byte, new_pos)) = next_byte(string, pos)? {
+        *out.get_mut(len).ok_or(HexError::OutputTooSmall)? = byte;
+        len += 1;
+        pos = new_pos;
+    }
+    Ok(len)
 }
 
 /// Compute length of a byte array which will be decoded from the strings.
@@ -43,9 +92,15 @@ pub const fn len(strings: &[&[u8]]) -> usize {
     let mut len = 0;
     while i < strings.len() {
         let mut pos = 0;
-        while let Some((_, new_pos)) = next_byte(strings[i], pos) {
-            len += 1;
-            pos = new_pos;
+        loop {
+            match next_byte(strings[i], pos) {
+                Ok(Some((_, new_pos))) => {
+                    len += 1;
+                    pos = new_pos;
+                }
+                Ok(None) => break,
+                Err(err) => panic!("{}", err.as_str()),
+            }
         }
         i += 1;
     }
@@ -62,10 +117,16 @@ pub const fn decode<const LEN: usize>(strings: &[&[u8]]) -> [u8; LEN] {
     let mut buf_pos = 0;
     while i < strings.len() {
         let mut pos = 0;
-        while let Some((byte, new_pos)) = next_byte(strings[i], pos) {
-            buf[buf_pos] = byte;
-            buf_pos += 1;
-            pos = new_pos;
+        loop {
+            match next_byte(strings[i], pos) {
+                Ok(Some((byte, new_pos))) => {
+                    buf[buf_pos] = byte;
+                    buf_pos += 1;
+                    pos = new_pos;
+                }
+                Ok(None) => break,
+                Err(err) => panic!("{}", err.as_str()),
+            }
         }
         i += 1;
     }
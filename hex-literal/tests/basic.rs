@@ -81,3 +81,21 @@ fn allows_various_spacing() {
 fn can_use_const() {
     const _: [u8; 4] = hex!("ff d3 01 7f");
 }
+
+#[test]
+fn decode_runtime() {
+    use hex_literal::{decode_runtime, HexError};
+
+    let mut buf = [0u8; 8];
+    assert_eq!(decode_runtime("01 dd f7\n7F", &mut buf), Ok(4));
+    assert_eq!(buf[..4], hex!("01 dd f7 7f"));
+    assert_eq!(decode_runtime("", &mut buf), Ok(0));
+
+    assert_eq!(decode_runtime("012", &mut buf), Err(HexError::OddLength));
+    assert_eq!(decode_runtime("0g", &mut buf), Err(HexError::InvalidChar));
+    assert_eq!(decode_runtime("АА", &mut buf), Err(HexError::NonAscii));
+    assert_eq!(
+        decode_runtime("000102030405060708", &mut buf),
+        Err(HexError::OutputTooSmall)
+    );
+}
@@ -2,7 +2,7 @@ use crate::{
     errors::{IntoArrayError, NotEqualError},
     InOut,
 };
-use core::{marker::PhantomData, slice};
+use core::{marker::PhantomData, ptr, slice};
 use hybrid_array::{Array, ArraySize};
 
 /// Custom slice type which references one immutable (input) slice and one
@@ -207,6 +207,16 @@ impl<'inp, 'out, T> InOutBuf<'inp, 'out, T> {
         )
     }
 
+    /// Convert buffer into [`InOut`] array.
+    ///
+    /// Returns an error if buffer length is not equal to `N`.
+    #[inline(always)]
+    pub fn try_into_array<N: ArraySize>(
+        self,
+    ) -> Result<InOut<'inp, 'out, Array<T, N>>, IntoArrayError> {
+        self.try_into()
+    }
+
     /// Partition buffer into 2 parts: buffer of arrays and tail.
     #[inline(always)]
     pub fn into_chunks<N: ArraySize>(
@@ -233,6 +243,20 @@ impl<'inp, 'out, T> InOutBuf<'inp, 'out, T> {
     }
 }
 
+impl<'inp, 'out, T: Copy> InOutBuf<'inp, 'out, T> {
+    /// Copy values behind the input slice to the output slice.
+    ///
+    /// This is a no-op if the input and output slices point to the same memory.
+    #[inline(always)]
+    pub fn copy_in2out(&mut self) {
+        if !ptr::eq(self.in_ptr, self.out_ptr) {
+            // SAFETY: `InOutBuf` guarantees that non-equal input and output
+            // pointers do not overlap and are valid for `len` elements.
+            unsafe { ptr::copy_nonoverlapping(self.in_ptr, self.out_ptr, self.len) }
+        }
+    }
+}
+
 impl<'inp, 'out> InOutBuf<'inp, 'out, u8> {
     /// XORs `data` with values behind the input slice and write
     /// result to the output slice.
@@ -1,11 +1,14 @@
-use crate::{errors::OutIsTooSmallError, InOutBuf};
+use crate::{
+    errors::{IntoArrayError, OutIsTooSmallError},
+    InOut, InOutBuf,
+};
 use core::{marker::PhantomData, slice};
+use hybrid_array::{Array, ArraySize};
 
 #[cfg(feature = "block-padding")]
 use {
-    crate::{errors::PadError, InOut},
+    crate::errors::PadError,
     block_padding::{PadType, Padding},
-    hybrid_array::{Array, ArraySize},
 };
 
 /// Custom slice type which references one immutable (input) slice and one
@@ -91,7 +94,7 @@ impl<T> InOutBufReserved<'_, '_, T> {
     /// Get output buffer length.
     #[inline(always)]
     pub fn get_out_len(&self) -> usize {
-        self.in_len
+        self.out_len
     }
 
     /// Split buffer into `InOutBuf` with input length and mutable slice pointing to
@@ -147,9 +150,60 @@ impl<'inp, 'out, T> InOutBufReserved<'inp, 'out, T> {
     pub fn into_out(self) -> &'out mut [T] {
         unsafe { slice::from_raw_parts_mut(self.out_ptr, self.out_len) }
     }
+
+    /// Split off the first `N` elements of the input and output buffers as
+    /// [`InOut`] array and return it together with the remaining buffer.
+    ///
+    /// Returns an error if the input buffer is shorter than `N`.
+    #[inline(always)]
+    pub fn split_array<N: ArraySize>(
+        self,
+    ) -> Result<(InOut<'inp, 'out, Array<T, N>>, Self), IntoArrayError> {
+        if self.in_len < N::USIZE {
+            return Err(IntoArrayError);
+        }
+        // SAFETY: we have verified that `N <= in_len <= out_len`, so the first
+        // `N` elements of both buffers are valid and the remaining parts
+        // do not overlap with them.
+        unsafe {
+            let head = InOut::from_raw(
+                self.in_ptr as *const Array<T, N>,
+                self.out_ptr as *mut Array<T, N>,
+            );
+            let tail = Self::from_raw(
+                self.in_ptr.add(N::USIZE),
+                self.in_len - N::USIZE,
+                self.out_ptr.add(N::USIZE),
+                self.out_len - N::USIZE,
+            );
+            Ok((head, tail))
+        }
+    }
 }
 
 impl<'inp, 'out> InOutBufReserved<'inp, 'out, u8> {
+    /// Split buffer into message [`InOutBuf`] and `N`-byte tag array stored in
+    /// the reserved space right after the message.
+    ///
+    /// Returns an error if the reserved space is smaller than `N`.
+    #[inline(always)]
+    pub fn split_tag<N: ArraySize>(
+        self,
+    ) -> Result<(InOutBuf<'inp, 'out, u8>, &'out mut Array<u8, N>), OutIsTooSmallError> {
+        // This never underflows because the type ensures that `out_len` is
+        // bigger or equal to `in_len`.
+        if self.out_len - self.in_len < N::USIZE {
+            return Err(OutIsTooSmallError);
+        }
+        // SAFETY: we have verified that `in_len + N <= out_len`, so the tag
+        // region is valid for writes and does not overlap with the message.
+        unsafe {
+            let msg = InOutBuf::from_raw(self.in_ptr, self.out_ptr, self.in_len);
+            let tag = &mut *(self.out_ptr.add(self.in_len) as *mut Array<u8, N>);
+            Ok((msg, tag))
+        }
+    }
+
     /// Transform buffer into [`PaddedInOutBuf`] using padding algorithm `P`.
     #[cfg(feature = "block-padding")]
     #[inline(always)]
@@ -0,0 +1,82 @@
+use hybrid_array::typenum::{U4, U5};
+use inout::InOutBuf;
+
+#[test]
+fn split_at_in_place() {
+    let mut buf = [1u8, 2, 3, 4, 5];
+    let (mut head, mut tail) = InOutBuf::from(&mut buf[..]).split_at(2);
+
+    assert_eq!(head.get_in(), [1, 2]);
+    assert_eq!(tail.get_in(), [3, 4, 5]);
+
+    // Both halves keep aliased input and output, so writes to the output
+    // are observed through the input.
+    head.xor_in2out(&[0xFF; 2]);
+    tail.xor_in2out(&[0xFF; 3]);
+    assert_eq!(head.get_in(), [0xFE, 0xFD]);
+    assert_eq!(tail.get_in(), [0xFC, 0xFB, 0xFA]);
+    assert_eq!(buf, [0xFE, 0xFD, 0xFC, 0xFB, 0xFA]);
+}
+
+#[test]
+fn split_at_buf_to_buf() {
+    let inp = [1u8, 2, 3, 4, 5];
+    let mut out = [0u8; 5];
+    let buf = InOutBuf::new(&inp, &mut out).unwrap();
+    let (mut head, mut tail) = buf.split_at(3);
+
+    head.xor_in2out(&[0xFF; 3]);
+    tail.xor_in2out(&[0xFF; 2]);
+    assert_eq!(head.get_in(), [1, 2, 3]);
+    assert_eq!(tail.get_in(), [4, 5]);
+    assert_eq!(out, [0xFE, 0xFD, 0xFC, 0xFB, 0xFA]);
+}
+
+#[test]
+fn split_at_bounds() {
+    let mut buf = [0u8; 4];
+    let (head, tail) = InOutBuf::from(&mut buf[..]).split_at(0);
+    assert!(head.is_empty());
+    assert_eq!(tail.len(), 4);
+
+    let (head, tail) = InOutBuf::from(&mut buf[..]).split_at(4);
+    assert_eq!(head.len(), 4);
+    assert!(tail.is_empty());
+}
+
+#[test]
+#[should_panic]
+fn split_at_out_of_bounds() {
+    let mut buf = [0u8; 4];
+    let _ = InOutBuf::from(&mut buf[..]).split_at(5);
+}
+
+#[test]
+fn try_into_array() {
+    let inp = [1u8, 2, 3, 4];
+    let mut out = [0u8; 4];
+
+    let buf = InOutBuf::new(&inp, &mut out).unwrap();
+    assert!(buf.try_into_array::<U5>().is_err());
+
+    let buf = InOutBuf::new(&inp, &mut out).unwrap();
+    let mut block = buf.try_into_array::<U4>().unwrap();
+    assert_eq!(block.get_in(), &[1, 2, 3, 4]);
+    block.get_out().copy_from_slice(&[5, 6, 7, 8]);
+    assert_eq!(out, [5, 6, 7, 8]);
+}
+
+#[test]
+fn copy_in2out_in_place() {
+    let mut buf = [1u8, 2, 3, 4];
+    InOutBuf::from(&mut buf[..]).copy_in2out();
+    assert_eq!(buf, [1, 2, 3, 4]);
+}
+
+#[test]
+fn copy_in2out_buf_to_buf() {
+    let inp = [1u8, 2, 3, 4];
+    let mut out = [0u8; 4];
+    InOutBuf::new(&inp, &mut out).unwrap().copy_in2out();
+    assert_eq!(out, inp);
+}
@@ -0,0 +1,101 @@
+use hybrid_array::{
+    typenum::{U16, U17},
+    Array,
+};
+use inout::InOutBufReserved;
+
+#[test]
+fn get_out_len() {
+    let inp = [0x42u8; 8];
+    let mut out = [0u8; 20];
+    let mut buf = InOutBufReserved::from_slices(&inp, &mut out).unwrap();
+    assert_eq!(buf.get_in_len(), 8);
+    assert_eq!(buf.get_out_len(), 20);
+
+    let (body, tail) = buf.split_reserved();
+    assert_eq!(body.len(), 8);
+    assert_eq!(tail.len(), 12);
+}
+
+#[test]
+fn split_array_in_place() {
+    let mut buf = [0u8; 64];
+    for (i, b) in buf.iter_mut().enumerate() {
+        *b = i as u8;
+    }
+    let mut rest = InOutBufReserved::from_mut_slice(&mut buf, 50).unwrap();
+
+    for i in 0..3 {
+        let (mut block, tail) = rest.split_array::<U16>().unwrap();
+        let start = 16 * i as u8;
+        let expected = Array::<u8, U16>::from_fn(|j| start + j as u8);
+        assert_eq!(block.get_in(), &expected);
+        block.xor_in2out(&Array([0xFF; 16]));
+        rest = tail;
+    }
+    assert_eq!(rest.get_in_len(), 2);
+    assert_eq!(rest.get_out_len(), 16);
+    assert!(rest.split_array::<U16>().is_err());
+
+    for (i, b) in buf.iter().enumerate() {
+        let expected = if i < 48 { !(i as u8) } else { i as u8 };
+        assert_eq!(*b, expected);
+    }
+}
+
+#[test]
+fn split_array_buf_to_buf() {
+    let inp = [0x42u8; 40];
+    let mut out = [0u8; 48];
+    let mut rest = InOutBufReserved::from_slices(&inp, &mut out).unwrap();
+
+    for _ in 0..2 {
+        let (mut block, tail) = rest.split_array::<U16>().unwrap();
+        block.xor_in2out(&Array([0x01; 16]));
+        rest = tail;
+    }
+    assert_eq!(rest.get_in_len(), 8);
+    assert_eq!(rest.get_out_len(), 16);
+    assert!(rest.split_array::<U16>().is_err());
+
+    assert_eq!(inp, [0x42; 40]);
+    assert_eq!(out[..32], [0x43; 32]);
+    assert_eq!(out[32..], [0; 16]);
+}
+
+#[test]
+fn split_tag_in_place() {
+    let mut buf = [0x42u8; 36];
+    let msg_len = 20;
+    let res = InOutBufReserved::from_mut_slice(&mut buf, msg_len).unwrap();
+    let (mut msg, tag) = res.split_tag::<U16>().unwrap();
+
+    assert_eq!(msg.len(), msg_len);
+    msg.xor_in2out(&[0x01; 20]);
+    tag.copy_from_slice(&[0xAA; 16]);
+
+    assert_eq!(buf[..msg_len], [0x43; 20]);
+    assert_eq!(buf[msg_len..], [0xAA; 16]);
+}
+
+#[test]
+fn split_tag_buf_to_buf() {
+    let inp = [0x42u8; 8];
+    let mut out = [0u8; 32];
+    let res = InOutBufReserved::from_slices(&inp, &mut out).unwrap();
+    let (mut msg, tag) = res.split_tag::<U16>().unwrap();
+
+    msg.xor_in2out(&[0x01; 8]);
+    tag.copy_from_slice(&[0xAA; 16]);
+
+    assert_eq!(out[..8], [0x43; 8]);
+    assert_eq!(out[8..24], [0xAA; 16]);
+    assert_eq!(out[24..], [0; 8]);
+}
+
+#[test]
+fn split_tag_too_small() {
+    let mut buf = [0u8; 36];
+    let res = InOutBufReserved::from_mut_slice(&mut buf, 20).unwrap();
+    assert!(res.split_tag::<U17>().is_err());
+}
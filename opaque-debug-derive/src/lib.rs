@@ -0,0 +1,44 @@
+//! Custom derive support for `opaque-debug`
+
+#![crate_type = "proc-macro"]
+#![forbid(unsafe_code)]
+#![warn(rust_2018_idioms, trivial_casts, unused_qualifications)]
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::DeriveInput;
+
+/// Derive an opaque `Debug` implementation.
+///
+/// The generated implementation is equivalent to the one produced by
+/// `opaque_debug::implement!`, with type parameters read from the item
+/// definition. Lifetime and const parameters are not printed.
+#[proc_macro_derive(OpaqueDebug)]
+pub fn derive_opaque_debug(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    derive_opaque_debug_impl(syn::parse_macro_input!(input as DeriveInput)).into()
+}
+
+fn derive_opaque_debug_impl(input: DeriveInput) -> TokenStream {
+    let ty_name = &input.ident;
+    let (impl_gen, type_gen, where_) = input.generics.split_for_impl();
+    let params: Vec<_> = input.generics.type_params().map(|p| &p.ident).collect();
+
+    let body = if params.is_empty() {
+        let fmt_str = format!("{} {{{{ ... }}}}", ty_name);
+        quote! { ::core::write!(f, #fmt_str) }
+    } else {
+        let placeholders = vec!["{}"; params.len()].join(", ");
+        let fmt_str = format!("{}<{}> {{{{ ... }}}}", ty_name, placeholders);
+        quote! {
+            ::core::write!(f, #fmt_str, #(::core::any::type_name::<#params>()),*)
+        }
+    };
+
+    quote! {
+        impl #impl_gen ::core::fmt::Debug for #ty_name #type_gen #where_ {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                #body
+            }
+        }
+    }
+}
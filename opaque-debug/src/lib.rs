@@ -31,12 +31,30 @@
 //! let val = GenericCryptoStuff { key: [42u8; 16] };
 //! assert_eq!(format!("{:?}", val), "GenericCryptoStuff<[u8; 16]> { ... }")
 //! ```
+//!
+//! With the `derive` feature enabled, the same implementation can be derived
+//! without listing the generic parameters by hand:
+//! ```
+//! # #[cfg(feature = "derive")]
+//! # {
+//! #[derive(opaque_debug::OpaqueDebug)]
+//! pub struct GenericCryptoStuff<K> {
+//!     key: K,
+//! }
+//!
+//! let val = GenericCryptoStuff { key: [42u8; 16] };
+//! assert_eq!(format!("{:?}", val), "GenericCryptoStuff<[u8; 16]> { ... }")
+//! # }
+//! ```
 #![no_std]
 #![doc(
     html_logo_url = "https://raw.githubusercontent.com/RustCrypto/media/6ee8e381/logo.svg",
     html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/media/6ee8e381/logo.svg"
 )]
 
+#[cfg(feature = "derive")]
+pub use opaque_debug_derive::OpaqueDebug;
+
 #[doc(hidden)]
 pub extern crate core as __core;
 
@@ -0,0 +1,52 @@
+//! Integration tests for the `OpaqueDebug` derive macro
+
+#![cfg(feature = "derive")]
+#![allow(dead_code)]
+
+use opaque_debug::OpaqueDebug;
+
+#[derive(OpaqueDebug)]
+struct Foo {
+    secret: u64,
+}
+
+#[derive(OpaqueDebug)]
+struct FooGeneric<'a, T, U: Copy, const N: usize>
+where
+    T: Default,
+{
+    secret: &'a [u8; N],
+    generic1: T,
+    generic2: U,
+}
+
+#[derive(OpaqueDebug)]
+enum FooEnum<T> {
+    A(T),
+    B,
+}
+
+#[test]
+fn derive_formatting() {
+    let s = format!("{:?}", Foo { secret: 42 });
+    assert_eq!(s, "Foo { ... }");
+}
+
+#[test]
+fn derive_formatting_generic() {
+    let s = format!(
+        "{:?}",
+        FooGeneric {
+            secret: &[42; 4],
+            generic1: (),
+            generic2: 0u8,
+        }
+    );
+    assert_eq!(s, "FooGeneric<(), u8> { ... }");
+}
+
+#[test]
+fn derive_formatting_enum() {
+    let s = format!("{:?}", FooEnum::<&str>::B);
+    assert_eq!(s, "FooEnum<&str> { ... }");
+}